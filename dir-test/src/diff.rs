@@ -0,0 +1,50 @@
+//! A small LCS-based line diff used to render a readable failure message
+//! when [`crate::Fixture::assert_snapshot`] finds a mismatch.
+
+use std::fmt::Write;
+
+/// Renders a line-based diff between `expected` and `actual`, prefixing
+/// deleted lines with `-`, inserted lines with `+` and unchanged lines with
+/// a single space, mirroring the output of a unified diff.
+pub(crate) fn line_diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+    let (n, m) = (old.len(), new.len());
+
+    // `dp[i][j]` holds the length of the longest common subsequence of
+    // `old[i..]` and `new[j..]`.
+    let mut dp = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            dp[i][j] = if old[i] == new[j] {
+                dp[i + 1][j + 1] + 1
+            } else {
+                dp[i + 1][j].max(dp[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old[i] == new[j] {
+            let _ = writeln!(out, " {}", old[i]);
+            i += 1;
+            j += 1;
+        } else if dp[i + 1][j] >= dp[i][j + 1] {
+            let _ = writeln!(out, "-{}", old[i]);
+            i += 1;
+        } else {
+            let _ = writeln!(out, "+{}", new[j]);
+            j += 1;
+        }
+    }
+    for line in &old[i..] {
+        let _ = writeln!(out, "-{line}");
+    }
+    for line in &new[j..] {
+        let _ = writeln!(out, "+{line}");
+    }
+
+    out
+}
@@ -57,10 +57,24 @@
 //! }
 //! ```
 //!
-//! **NOTE**: The `dir` argument must be specified in an absolute path because
-//! of the limitation of the current procedural macro system. Consider using
-//! environment variables, `dir-test` crate resolves environment variables
-//! internally.
+//! **NOTE**: A relative `dir` is resolved against `CARGO_MANIFEST_DIR`, so
+//! `dir: "fixtures"` above works without spelling out the environment
+//! variable. Absolute paths and `$VAR` components are still accepted as
+//! before; use the `base` argument to anchor a relative `dir` somewhere
+//! else.
+//!
+//! ```rust, no_run
+//! use dir_test::{dir_test, Fixture};
+//!
+//! #[dir_test(
+//!     dir: "fixtures",
+//!     glob: "**/*.txt",
+//!     base: "$CARGO_MANIFEST_DIR",
+//! )]
+//! fn test(fixture: Fixture<&str>) {
+//!     // ...
+//! }
+//! ```
 //!
 //! ### Custom Loader
 //! You can specify a custom loader function to load the file content from the
@@ -122,6 +136,90 @@
 //!     // ...
 //! }
 //! ```
+//!
+//! ### Snapshot Testing
+//! Specifying `snapshot_ext` pairs each fixture with an expected-output file
+//! (the fixture path with its extension replaced by `snapshot_ext`) and makes
+//! `fixture.assert_snapshot(actual)` available in the test body. `snapshot_dir`
+//! can be set to keep the snapshots in a separate directory from the fixtures.
+//!
+//! ```rust, no_run
+//! use dir_test::{dir_test, Fixture};
+//!
+//! #[dir_test(
+//!     dir: "$CARGO_MANIFEST_DIR/fixtures",
+//!     glob: "**/*.txt",
+//!     snapshot_ext: "snap",
+//! )]
+//! fn test(fixture: Fixture<&str>) {
+//!     let actual = fixture.content().to_uppercase();
+//!     fixture.assert_snapshot(actual);
+//! }
+//! ```
+//!
+//! On a mismatch, `assert_snapshot` panics with a line-based diff of the
+//! expected and actual content. Run the test suite with the `DIR_TEST_UPDATE`
+//! environment variable set to write the actual content to the snapshot file
+//! instead, creating it if it doesn't exist yet.
+//!
+//! ```sh
+//! DIR_TEST_UPDATE=1 cargo test
+//! ```
+//!
+//! ### Inline Directives
+//! Setting `directive_prefix` makes the macro scan each fixture's leading
+//! lines for directive comments and splice the corresponding attributes
+//! onto that fixture's generated test, so a single fixture can opt out or
+//! declare an expected failure without touching the shared test function.
+//! Scanning stops at the first line that isn't a directive.
+//!
+//! ```rust, no_run
+//! use dir_test::{dir_test, Fixture};
+//!
+//! #[dir_test(
+//!     dir: "$CARGO_MANIFEST_DIR/fixtures",
+//!     glob: "**/*.txt",
+//!     directive_prefix: "//@",
+//! )]
+//! fn test(fixture: Fixture<&str>) {
+//!     // ...
+//! }
+//! ```
+//!
+//! ```text
+//! //@ ignore
+//! //@ should_panic
+//! //@ should_panic: "expected panic message"
+//! //@ attr: #[cfg(target_family = "wasm")]
+//! ```
+//!
+//! ### Grouping Multiple Files Into One Fixture
+//! When a test case is spread across several files (e.g. an input and its
+//! expected output), `group: "dir"` groups all files under each leaf
+//! directory into one fixture, and `group: "stem"` groups files sharing a
+//! file stem (e.g. `case1.in` and `case1.out`) instead. The test function
+//! then receives a `Fixture<Vec<(&'static str, T)>>`, with each member keyed
+//! by its extension (or file name, if it has none); `test_name` is derived
+//! from the group's directory or stem rather than a single file.
+//!
+//! ```rust, no_run
+//! use dir_test::{dir_test, Fixture};
+//!
+//! #[dir_test(
+//!     dir: "$CARGO_MANIFEST_DIR/fixtures",
+//!     glob: "**/*",
+//!     group: "stem",
+//! )]
+//! fn test(fixture: Fixture<Vec<(&'static str, &str)>>) {
+//!     let members = fixture.content();
+//!     let input = members.iter().find(|(ext, _)| *ext == "in").unwrap().1;
+//!     let expected = members.iter().find(|(ext, _)| *ext == "out").unwrap().1;
+//!
+//!     // ...
+//! }
+//! ```
+
+mod diff;
 
 /// A fixture contains a file content and its absolute path.
 /// Content type is determined by the loader function specified in
@@ -130,13 +228,28 @@
 pub struct Fixture<T> {
     content: T,
     path: &'static str,
+    snapshot_path: Option<&'static str>,
 }
 
 impl<T> Fixture<T> {
     #[doc(hidden)]
     /// Creates a new fixture from the given content and path.
     pub fn new(content: T, path: &'static str) -> Self {
-        Self { content, path }
+        Self {
+            content,
+            path,
+            snapshot_path: None,
+        }
+    }
+
+    #[doc(hidden)]
+    /// Creates a new fixture paired with a snapshot file path.
+    pub fn new_with_snapshot(content: T, path: &'static str, snapshot_path: &'static str) -> Self {
+        Self {
+            content,
+            path,
+            snapshot_path: Some(snapshot_path),
+        }
     }
 
     /// Returns the content of the fixture.
@@ -148,6 +261,47 @@ impl<T> Fixture<T> {
     pub const fn path(&self) -> &'static str {
         self.path
     }
+
+    /// Compares `actual` against this fixture's snapshot file, panicking
+    /// with a line diff on mismatch.
+    ///
+    /// If the `DIR_TEST_UPDATE` environment variable is set, writes `actual`
+    /// to the snapshot path instead of comparing, creating the file if it
+    /// doesn't exist yet. Requires `snapshot_ext` to have been set on the
+    /// `#[dir_test]` attribute.
+    pub fn assert_snapshot(&self, actual: impl std::fmt::Display) {
+        let snapshot_path = self.snapshot_path.unwrap_or_else(|| {
+            panic!("`assert_snapshot` requires `snapshot_ext` to be set in `#[dir_test]`")
+        });
+        let actual = actual.to_string();
+
+        if std::env::var_os("DIR_TEST_UPDATE").is_some() {
+            if let Some(parent) = std::path::Path::new(snapshot_path).parent() {
+                std::fs::create_dir_all(parent).unwrap_or_else(|e| {
+                    panic!(
+                        "failed to create snapshot directory `{}`: {e}",
+                        parent.display()
+                    )
+                });
+            }
+            std::fs::write(snapshot_path, &actual)
+                .unwrap_or_else(|e| panic!("failed to write snapshot `{snapshot_path}`: {e}"));
+            return;
+        }
+
+        let expected = std::fs::read_to_string(snapshot_path).unwrap_or_else(|_| {
+            panic!(
+                "snapshot `{snapshot_path}` does not exist, run with `DIR_TEST_UPDATE=1` to create it"
+            )
+        });
+
+        if expected != actual {
+            panic!(
+                "snapshot mismatch for `{snapshot_path}`\n{}",
+                diff::line_diff(&expected, &actual)
+            );
+        }
+    }
 }
 
 /// A procedural macro to generate test cases from files in a directory.
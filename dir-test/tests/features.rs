@@ -0,0 +1,70 @@
+use dir_test::{dir_test, Fixture};
+
+#[dir_test(
+    dir: "$CARGO_MANIFEST_DIR/tests/fixtures/pass/snapshot",
+    glob: "**/*.txt",
+    snapshot_ext: "snap",
+)]
+fn snapshot(fixture: Fixture<&str>) {
+    fixture.assert_snapshot(fixture.content().to_uppercase());
+}
+
+#[dir_test(
+    dir: "$CARGO_MANIFEST_DIR/tests/fixtures/pass/directives",
+    glob: "**/*.txt",
+    directive_prefix: "//@",
+)]
+fn directives(fixture: Fixture<&str>) {
+    if fixture.path().ends_with("panics.txt") {
+        panic!("boom");
+    }
+    if fixture.path().ends_with("ignored.txt") {
+        panic!("this fixture is marked `//@ ignore` and must not run");
+    }
+    // `plain.txt` and `cfg_linux.txt` (cfg-gated to `target_os = "linux"`
+    // via `//@ attr:`) just need to not panic.
+}
+
+#[dir_test(
+    dir: "$CARGO_MANIFEST_DIR/tests/fixtures/pass/group_dir",
+    glob: "**/*",
+    group: "dir",
+)]
+fn group_dir(fixture: Fixture<Vec<(&'static str, &str)>>) {
+    let members = fixture.content();
+    let input = members.iter().find(|(ext, _)| *ext == "in").unwrap().1;
+    let expected = members.iter().find(|(ext, _)| *ext == "out").unwrap().1;
+
+    let sum: i64 = input
+        .split_whitespace()
+        .map(|n| n.parse::<i64>().unwrap())
+        .sum();
+    assert_eq!(sum.to_string(), expected.trim());
+}
+
+#[dir_test(
+    dir: "$CARGO_MANIFEST_DIR/tests/fixtures/pass/group_stem",
+    glob: "**/*",
+    group: "stem",
+)]
+fn group_stem(fixture: Fixture<Vec<(&'static str, &str)>>) {
+    let members = fixture.content();
+    let input = members.iter().find(|(ext, _)| *ext == "in").unwrap().1;
+    let expected = members.iter().find(|(ext, _)| *ext == "out").unwrap().1;
+
+    let sum: i64 = input
+        .split_whitespace()
+        .map(|n| n.parse::<i64>().unwrap())
+        .sum();
+    assert_eq!(sum.to_string(), expected.trim());
+}
+
+// `dir` has no `$CARGO_MANIFEST_DIR` prefix; it's resolved against it
+// automatically since it's relative.
+#[dir_test(
+    dir: "tests/fixtures/pass/group_stem",
+    glob: "*.in",
+)]
+fn relative_dir(fixture: Fixture<&str>) {
+    assert!(fixture.path().ends_with(".in"));
+}
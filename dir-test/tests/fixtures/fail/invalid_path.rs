@@ -1,5 +1,7 @@
 use dir_test::dir_test;
 
+// `dir` is relative, so it's joined onto `CARGO_MANIFEST_DIR` before the
+// existence check; the joined path just doesn't exist.
 #[dir_test(
     dir: "../foo/"
 )]
@@ -10,4 +12,13 @@ fn foo(fixture: Fixture<&str>) {}
 )]
 fn foo(fixture: Fixture<&str>) {}
 
+// `base` itself must resolve to an absolute path; a relative `base` is
+// rejected with the "`base` must resolve to an absolute path" error before
+// `dir` is joined onto it.
+#[dir_test(
+    dir: "foo",
+    base: "not/absolute"
+)]
+fn foo(fixture: Fixture<&str>) {}
+
 fn main() {}
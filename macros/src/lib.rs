@@ -65,13 +65,24 @@ impl TestBuilder {
             )
         })?;
 
+        let mut entries: Vec<PathBuf> = paths
+            .filter_map(|p| p.ok())
+            .filter(|p| p.is_file())
+            .collect();
+        entries.sort();
+
         let mut tests = vec![];
-        for entry in paths.filter_map(|p| p.ok()) {
-            if !entry.is_file() {
-                continue;
+        match self.dir_test_arg.group_by {
+            Some(group_by) => {
+                for (group_key, members) in group_entries(entries, group_by) {
+                    tests.push(self.build_grouped_test(&group_key, &members)?);
+                }
+            }
+            None => {
+                for entry in entries {
+                    tests.push(self.build_test(&entry)?);
+                }
             }
-
-            tests.push(self.build_test(&entry)?);
         }
 
         Ok((
@@ -94,15 +105,148 @@ impl TestBuilder {
             None => quote! {::core::include_str!},
         };
 
+        let fixture = match self.snapshot_path(file_path)? {
+            Some(snapshot_path) => {
+                let snapshot_path_str = snapshot_path.to_string_lossy();
+                quote! {
+                    ::dir_test::Fixture::new_with_snapshot(#loader(#file_path_str), #file_path_str, #snapshot_path_str)
+                }
+            }
+            None => quote! {
+                ::dir_test::Fixture::new(#loader(#file_path_str), #file_path_str)
+            },
+        };
+
+        let directive_attrs = self.parse_directives(file_path)?;
+
         Ok(quote! {
             #(#test_attrs)*
+            #(#directive_attrs)*
             #[test]
             fn #test_name() #return_ty {
-                #test_func(::dir_test::Fixture::new(#loader(#file_path_str), #file_path_str))
+                #test_func(#fixture)
             }
         })
     }
 
+    /// Builds one test for a group of fixtures sharing `group_key` (either a
+    /// leaf directory or a common file stem, depending on `group: "dir"` /
+    /// `group: "stem"`). The test function receives a
+    /// `Fixture<Vec<(&'static str, T)>>`, keyed by each member's extension
+    /// (or file name, if it has none).
+    fn build_grouped_test(
+        &self,
+        group_key: &Path,
+        members: &[PathBuf],
+    ) -> Result<proc_macro2::TokenStream> {
+        let test_func = &self.func.sig.ident;
+        let test_name = self.group_test_name(test_func.to_string(), group_key)?;
+        let return_ty = &self.func.sig.output;
+        let test_attrs = &self.test_attrs;
+        let group_key_str = group_key.to_string_lossy();
+
+        let loader = match self.dir_test_arg.loader {
+            Some(ref loader) => quote! {#loader},
+            None => quote! {::core::include_str!},
+        };
+
+        let mut members = members.to_vec();
+        members.sort();
+
+        let entries = members.iter().map(|member_path| {
+            let member_path_str = member_path.to_string_lossy();
+            let key = member_key(member_path);
+            quote! { (#key, #loader(#member_path_str)) }
+        });
+
+        Ok(quote! {
+            #(#test_attrs)*
+            #[test]
+            fn #test_name() #return_ty {
+                #test_func(::dir_test::Fixture::new(vec![#(#entries),*], #group_key_str))
+            }
+        })
+    }
+
+    /// Scans the leading lines of `file_path` for `directive_prefix`
+    /// directives (e.g. `//@ ignore`) and turns them into attributes spliced
+    /// onto the generated `#[test]` fn. Scanning stops at the first line
+    /// that isn't a directive. No-op unless `directive_prefix` is set.
+    fn parse_directives(&self, file_path: &Path) -> Result<Vec<syn::Attribute>> {
+        let Some(prefix) = &self.dir_test_arg.directive_prefix else {
+            return Ok(vec![]);
+        };
+        let prefix = prefix.value();
+
+        let content = std::fs::read_to_string(file_path).map_err(|e| {
+            Error::new(
+                Span::call_site(),
+                format!("failed to read `{}` for directives: {e}", file_path.display()),
+            )
+        })?;
+
+        let mut attrs = vec![];
+        for line in content.lines() {
+            let Some(directive) = line.trim_start().strip_prefix(&prefix) else {
+                break;
+            };
+            let directive = directive.trim();
+
+            if directive == "ignore" {
+                attrs.push(syn::parse_quote!(#[ignore]));
+            } else if directive == "should_panic" {
+                attrs.push(syn::parse_quote!(#[should_panic]));
+            } else if let Some(msg) = directive.strip_prefix("should_panic:") {
+                let msg: syn::LitStr = syn::parse_str(msg.trim())?;
+                attrs.push(syn::parse_quote!(#[should_panic(expected = #msg)]));
+            } else if let Some(attr) = directive.strip_prefix("attr:") {
+                attrs.extend(syn::parse::Parser::parse_str(
+                    syn::Attribute::parse_outer,
+                    attr.trim(),
+                )?);
+            } else {
+                return Err(Error::new(
+                    Span::call_site(),
+                    format!(
+                        "unknown directive `{prefix}{directive}` in `{}`",
+                        file_path.display()
+                    ),
+                ));
+            }
+        }
+
+        Ok(attrs)
+    }
+
+    /// Resolves the snapshot file path for `file_path` when `snapshot_ext`
+    /// is set, joining it onto `snapshot_dir` if one was given.
+    fn snapshot_path(&self, file_path: &Path) -> Result<Option<PathBuf>> {
+        let Some(ext) = &self.dir_test_arg.snapshot_ext else {
+            return Ok(None);
+        };
+
+        let snapshot_file = file_path.with_extension(ext.value());
+
+        let path = match &self.dir_test_arg.snapshot_dir {
+            Some(dir) => {
+                let mut dir_path = self.dir_test_arg.resolve_path(Path::new(&dir.value()))?;
+                if dir_path.is_relative() {
+                    dir_path = self.dir_test_arg.resolve_dir()?.join(dir_path);
+                }
+                // Preserve the fixture's subdirectories under `snapshot_dir`
+                // so fixtures in different directories sharing a basename
+                // (e.g. `a/foo.txt`, `b/foo.txt`) don't collide.
+                let rel_snapshot_file = snapshot_file
+                    .strip_prefix(self.dir_test_arg.resolve_dir()?)
+                    .unwrap();
+                dir_path.join(rel_snapshot_file)
+            }
+            None => snapshot_file,
+        };
+
+        Ok(Some(path))
+    }
+
     fn test_name(&self, test_func_name: String, fixture_path: &Path) -> Result<syn::Ident> {
         assert!(fixture_path.is_file());
 
@@ -110,26 +254,48 @@ impl TestBuilder {
         let rel_path = fixture_path.strip_prefix(dir_path).unwrap();
         assert!(rel_path.is_relative());
 
+        self.name_from_rel_path(test_func_name, rel_path)
+    }
+
+    /// Like [`Self::test_name`], but derives the name from a group key (a
+    /// leaf directory or a common file stem) instead of a single file.
+    fn group_test_name(&self, test_func_name: String, group_key: &Path) -> Result<syn::Ident> {
+        let dir_path = self.dir_test_arg.resolve_dir()?;
+        let rel_path = group_key.strip_prefix(dir_path).unwrap();
+        assert!(rel_path.is_relative());
+
+        self.name_from_rel_path(test_func_name, rel_path)
+    }
+
+    fn name_from_rel_path(&self, test_func_name: String, rel_path: &Path) -> Result<syn::Ident> {
         let mut test_name = test_func_name;
         test_name.push_str("__");
 
         let components: Vec<_> = rel_path.iter().collect();
 
-        for component in &components[0..components.len() - 1] {
-            let component = component
-                .to_string_lossy()
-                .replace(|c: char| c.is_ascii_punctuation(), "_");
-            test_name.push_str(&component);
-            test_name.push('_');
-        }
+        // `rel_path` is empty for a `group: "dir"` group whose members sit
+        // directly under `dir` (their parent strips down to nothing), so
+        // there's no trailing component to name the test after.
+        if components.is_empty() {
+            test_name.push_str("root");
+        } else {
+            for component in &components[0..components.len() - 1] {
+                let component = component
+                    .to_string_lossy()
+                    .replace(|c: char| c.is_ascii_punctuation(), "_");
+                test_name.push_str(&component);
+                test_name.push('_');
+            }
 
-        test_name.push_str(
-            &rel_path
+            let stem = rel_path
                 .file_stem()
-                .unwrap()
-                .to_string_lossy()
-                .replace(|c: char| c.is_ascii_punctuation(), "_"),
-        );
+                .unwrap_or_else(|| components[components.len() - 1]);
+            test_name.push_str(
+                &stem
+                    .to_string_lossy()
+                    .replace(|c: char| c.is_ascii_punctuation(), "_"),
+            );
+        }
 
         if let Some(postfix) = &self.dir_test_arg.postfix {
             test_name.push('_');
@@ -175,6 +341,61 @@ struct DirTestArg {
     glob: Option<syn::LitStr>,
     postfix: Option<syn::LitStr>,
     loader: Option<syn::Path>,
+    snapshot_ext: Option<syn::LitStr>,
+    snapshot_dir: Option<syn::LitStr>,
+    directive_prefix: Option<syn::LitStr>,
+    group_by: Option<GroupBy>,
+    base: Option<syn::LitStr>,
+}
+
+/// How fixtures are bucketed into groups when `group` is set.
+#[derive(Clone, Copy)]
+enum GroupBy {
+    /// Group all files under each leaf directory.
+    Dir,
+    /// Group files sharing a file stem, e.g. `case1.in` / `case1.out`.
+    Stem,
+}
+
+impl GroupBy {
+    fn parse(lit: &syn::LitStr) -> Result<Self> {
+        match lit.value().as_str() {
+            "dir" => Ok(GroupBy::Dir),
+            "stem" => Ok(GroupBy::Stem),
+            other => Err(Error::new_spanned(
+                lit,
+                format!("unknown `group` value `{other}`, expected `dir` or `stem`"),
+            )),
+        }
+    }
+}
+
+/// Buckets `entries` into groups keyed by leaf directory or file stem,
+/// preserving the relative order entries were first seen in.
+fn group_entries(entries: Vec<PathBuf>, group_by: GroupBy) -> Vec<(PathBuf, Vec<PathBuf>)> {
+    let mut groups: Vec<(PathBuf, Vec<PathBuf>)> = vec![];
+
+    for entry in entries {
+        let key = match group_by {
+            GroupBy::Dir => entry.parent().unwrap().to_path_buf(),
+            GroupBy::Stem => entry.with_extension(""),
+        };
+
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, members)) => members.push(entry),
+            None => groups.push((key, vec![entry])),
+        }
+    }
+
+    groups
+}
+
+/// The key a group member is exposed under in its `Fixture`'s member list:
+/// its extension, or its file name if it has none.
+fn member_key(path: &Path) -> String {
+    path.extension()
+        .map(|ext| ext.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.file_name().unwrap().to_string_lossy().into_owned())
 }
 
 impl DirTestArg {
@@ -183,7 +404,10 @@ impl DirTestArg {
             return Err(Error::new(Span::call_site(), "`dir` is required"));
         };
 
-        let resolved = self.resolve_path(Path::new(&dir.value()))?;
+        let mut resolved = self.resolve_path(Path::new(&dir.value()))?;
+        if resolved.is_relative() {
+            resolved = self.base_dir()?.join(resolved);
+        }
 
         if !resolved.is_absolute() {
             return Err(Error::new_spanned(
@@ -205,6 +429,33 @@ impl DirTestArg {
         Ok(resolved)
     }
 
+    /// Resolves the base directory a relative `dir` is joined onto: the
+    /// `base` arg if given, otherwise `CARGO_MANIFEST_DIR`.
+    fn base_dir(&self) -> Result<PathBuf> {
+        let Some(base) = &self.base else {
+            let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").map_err(|e| {
+                Error::new(
+                    Span::call_site(),
+                    format!("failed to resolve env var `CARGO_MANIFEST_DIR`: {e}"),
+                )
+            })?;
+            return Ok(PathBuf::from(manifest_dir));
+        };
+
+        let resolved = self.resolve_path(Path::new(&base.value()))?;
+        if !resolved.is_absolute() {
+            return Err(Error::new_spanned(
+                base.clone(),
+                format!(
+                    "`base` must resolve to an absolute path, got `{}`",
+                    resolved.display()
+                ),
+            ));
+        }
+
+        Ok(resolved)
+    }
+
     fn resolve_path(&self, path: &Path) -> Result<PathBuf> {
         let mut resolved = PathBuf::new();
         for component in path {
@@ -265,6 +516,32 @@ impl syn::parse::Parse for DirTestArg {
                     dir_test_attr.loader = Some(input.parse()?);
                 }
 
+                "snapshot_ext" => {
+                    input.parse::<Token![:]>()?;
+                    dir_test_attr.snapshot_ext = Some(input.parse()?);
+                }
+
+                "snapshot_dir" => {
+                    input.parse::<Token![:]>()?;
+                    dir_test_attr.snapshot_dir = Some(input.parse()?);
+                }
+
+                "directive_prefix" => {
+                    input.parse::<Token![:]>()?;
+                    dir_test_attr.directive_prefix = Some(input.parse()?);
+                }
+
+                "group" => {
+                    input.parse::<Token![:]>()?;
+                    let lit: syn::LitStr = input.parse()?;
+                    dir_test_attr.group_by = Some(GroupBy::parse(&lit)?);
+                }
+
+                "base" => {
+                    input.parse::<Token![:]>()?;
+                    dir_test_attr.base = Some(input.parse()?);
+                }
+
                 _ => {
                     return Err(Error::new_spanned(
                         arg.clone(),